@@ -23,10 +23,34 @@
 //! [`usize`]: https://doc.rust-lang.org/std/primitive.usize.html
 //! [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
 //! [Base62]: https://en.wikipedia.org/wiki/Base62
+//!
+//! # Beyond `usize`
+//!
+//! - [`Alphabet`] lets [`encode_with`]/[`decode_with`] use a digit ordering
+//!   other than the default, e.g. to interop with other Base62 tools.
+//! - [`encode_bytes`]/[`decode_bytes`] convert a `&[u8]` of any length
+//!   instead of a single integer.
+//! - [`encode_int`]/[`decode_int`] (and the width-suffixed helpers like
+//!   [`decode_u128`]) work over any [`Base62Int`] width, not just `usize`.
+//! - [`Base62`] is a `usize` newtype with [`FromStr`](core::str::FromStr)
+//!   and [`Display`](core::fmt::Display) impls for idiomatic parsing.
+//!
+//! # `no_std`
+//!
+//! The `std` feature is enabled by default. Build with `default-features =
+//! false` for `no_std` (`alloc` is still required); [`Base62Error`]'s
+//! [`Display`](core::fmt::Display) and [`std::error::Error`] impls are only
+//! available with `std` enabled.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use std::char;
-use std::error::Error;
-use std::fmt;
+extern crate alloc;
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter;
 
 const ALPHANUMERIC: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
 const BASE: usize = 62;
@@ -57,28 +81,129 @@ pub enum Base62Error {
     /// );
     /// ```
     Overflow,
+    /// Occurs when building an [`Alphabet`] from characters that are not 62
+    /// unique ASCII alphanumeric characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use base62num::{Alphabet, Base62Error};
+    ///
+    /// let mut chars = ['0'; 62];
+    /// chars[1] = '0'; // duplicate
+    /// assert_eq!(Alphabet::new(chars), Err(Base62Error::InvalidAlphabet));
+    /// ```
+    InvalidAlphabet,
 }
 
-impl fmt::Display for Base62Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+#[cfg(feature = "std")]
+impl std::fmt::Display for Base62Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match *self {
             Base62Error::NonAlphanumeric => write!(f, "Input contains non-alphanumeric."),
             Base62Error::Overflow => write!(f, "Return is overflow."),
+            Base62Error::InvalidAlphabet => {
+                write!(f, "Alphabet is not 62 unique ASCII alphanumeric characters.")
+            }
         }
     }
 }
 
-impl Error for Base62Error {
+#[cfg(feature = "std")]
+impl std::error::Error for Base62Error {
     fn description(&self) -> &str {
         match *self {
             Base62Error::NonAlphanumeric => "contains non-alphanumeric",
             Base62Error::Overflow => "overflow",
+            Base62Error::InvalidAlphabet => "invalid alphabet",
         }
     }
 }
 
-fn to_char(num: usize) -> Option<char> {
-    ALPHANUMERIC.chars().nth(num)
+/// A set of 62 unique ASCII alphanumeric characters used to encode and
+/// decode Base62 digits.
+///
+/// The default alphabet (see [`Alphabet::default`]) orders digits as
+/// uppercase, then lowercase, then numerals, matching [`encode`]/[`decode`].
+/// Other Base62 deployments disagree on ordering, e.g. the "GMP" variant
+/// orders digits first (`0-9A-Za-z`); [`encode_with`]/[`decode_with`] accept
+/// any such alphabet.
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::{encode_with, Alphabet};
+///
+/// let mut chars = ['\0'; 62];
+/// for (i, c) in "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+///     .chars()
+///     .enumerate()
+/// {
+///     chars[i] = c;
+/// }
+/// let gmp = Alphabet::new(chars).unwrap();
+/// assert_eq!(encode_with(123, &gmp), "1z");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alphabet([char; BASE]);
+
+impl Alphabet {
+    /// Builds an [`Alphabet`] from 62 characters, validating that every
+    /// character is ASCII alphanumeric and that none repeats.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use base62num::Alphabet;
+    ///
+    /// let mut chars = ['0'; 62];
+    /// chars[1] = '0'; // duplicate
+    /// assert!(Alphabet::new(chars).is_err());
+    /// ```
+    pub fn new(chars: [char; BASE]) -> Result<Self, Base62Error> {
+        let mut seen = BTreeMap::new();
+        for (i, &c) in chars.iter().enumerate() {
+            if !c.is_ascii_alphanumeric() || seen.insert(c, i).is_some() {
+                return Err(Base62Error::InvalidAlphabet);
+            }
+        }
+        Ok(Alphabet(chars))
+    }
+
+    fn to_char(&self, num: usize) -> Option<char> {
+        self.0.get(num).copied()
+    }
+
+    fn reverse_lookup(&self) -> BTreeMap<char, usize> {
+        self.0.iter().copied().zip(0..BASE).collect()
+    }
+}
+
+impl Default for Alphabet {
+    /// The `ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789`
+    /// ordering used by the plain [`encode`]/[`decode`] functions.
+    fn default() -> Self {
+        let mut chars = ['\0'; BASE];
+        for (i, c) in ALPHANUMERIC.chars().enumerate() {
+            chars[i] = c;
+        }
+        Alphabet(chars)
+    }
+}
+
+/// Converts a number into a string in Base62 using a custom [`Alphabet`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// use base62num::{encode_with, Alphabet};
+///
+/// assert_eq!(encode_with(123, &Alphabet::default()), "B9");
+/// ```
+pub fn encode_with(num: usize, alphabet: &Alphabet) -> String {
+    encode_int_with(num, alphabet)
 }
 
 /// Converts a number into a string in Base62.
@@ -94,23 +219,21 @@ fn to_char(num: usize) -> Option<char> {
 /// assert_eq!(encode(123), "B9");
 /// ```
 pub fn encode(num: usize) -> String {
-    let mut digits = vec![];
-    let mut n = num;
-    while n > 0 {
-        let rem = n % BASE;
-        n = (n - rem) / BASE;
-        match to_char(rem) {
-            Some(c) => digits.push(c),
-            None => unreachable!(),
-        };
-    }
-    digits.iter().rev().collect()
+    encode_with(num, &Alphabet::default())
 }
 
-fn to_num(c: char) -> Result<usize, Base62Error> {
-    ALPHANUMERIC
-        .find(|x| x == c)
-        .ok_or(Base62Error::NonAlphanumeric)
+/// Converts a string in Base62 into an number using a custom [`Alphabet`].
+///
+/// # Examples
+///
+/// Basic usage:
+/// ```rust
+/// use base62num::{decode_with, Alphabet};
+///
+/// assert_eq!(decode_with("B9", &Alphabet::default()), Ok(123));
+/// ```
+pub fn decode_with(input: &str, alphabet: &Alphabet) -> Result<usize, Base62Error> {
+    decode_int_with(input, alphabet)
 }
 
 /// Converts a string in Base62 into an number.
@@ -130,18 +253,446 @@ fn to_num(c: char) -> Result<usize, Base62Error> {
 /// );
 ///
 pub fn decode(input: &str) -> Result<usize, Base62Error> {
-    input.chars().try_fold(0 as usize, |acc, c| {
-        to_num(c).and_then(|x| {
-            acc.checked_mul(BASE)
-                .and_then(|mul| mul.checked_add(x))
-                .ok_or(Base62Error::Overflow)
-        })
+    decode_with(input, &Alphabet::default())
+}
+
+fn is_zero(num: &[u8]) -> bool {
+    num.iter().all(|&b| b == 0)
+}
+
+/// Divides a big-endian byte buffer in place by [`BASE`](BASE) and returns
+/// the remainder, using the schoolbook long-division algorithm.
+fn divmod62(num: &mut [u8]) -> u8 {
+    let mut rem: u32 = 0;
+    for byte in num.iter_mut() {
+        let cur = rem * 256 + *byte as u32;
+        *byte = (cur / BASE as u32) as u8;
+        rem = cur % BASE as u32;
+    }
+    rem as u8
+}
+
+/// Multiplies a little-endian byte buffer by [`BASE`](BASE) and adds a
+/// digit, growing the buffer on overflow.
+///
+/// The buffer is kept least-significant-byte-first so that growth is a
+/// `push` rather than an `insert(0, ..)`, which would shift the whole
+/// accumulator on every carry. Callers that need the usual big-endian byte
+/// order must `reverse()` once after accumulation finishes.
+fn mul_add62(num: &mut Vec<u8>, digit: u8) {
+    let mut carry = digit as u32;
+    for byte in num.iter_mut() {
+        let cur = *byte as u32 * BASE as u32 + carry;
+        *byte = (cur % 256) as u8;
+        carry = cur / 256;
+    }
+    while carry > 0 {
+        num.push((carry % 256) as u8);
+        carry /= 256;
+    }
+}
+
+/// Converts a byte slice into a string in Base62 using a custom
+/// [`Alphabet`], treating `bytes` as a big-endian arbitrary-precision
+/// number.
+///
+/// Each leading zero byte is preserved as a leading `alphabet`-first
+/// character, mirroring how leading zero bytes are significant for hashes,
+/// UUIDs and similar fixed-width tokens.
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::{encode_bytes_with, Alphabet};
+///
+/// assert_eq!(encode_bytes_with(&[0, 0, 1], &Alphabet::default()), "AAB");
+/// assert_eq!(encode_bytes_with(&[], &Alphabet::default()), "");
+/// ```
+pub fn encode_bytes_with(bytes: &[u8], alphabet: &Alphabet) -> String {
+    let leading_zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    let mut magnitude = bytes[leading_zeros..].to_vec();
+    let mut digits = vec![];
+    while !is_zero(&magnitude) {
+        let rem = divmod62(&mut magnitude);
+        match alphabet.to_char(rem as usize) {
+            Some(c) => digits.push(c),
+            None => unreachable!(),
+        };
+    }
+    let zero_char = alphabet.to_char(0).unwrap_or_else(|| unreachable!());
+    let mut result: String = iter::repeat_n(zero_char, leading_zeros).collect();
+    result.extend(digits.iter().rev());
+    result
+}
+
+/// Converts a byte slice into a string in Base62.
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::encode_bytes;
+///
+/// assert_eq!(encode_bytes(&[0, 0, 1]), "AAB");
+/// assert_eq!(encode_bytes(&[]), "");
+/// ```
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    encode_bytes_with(bytes, &Alphabet::default())
+}
+
+/// Converts a string in Base62 into a byte vector using a custom
+/// [`Alphabet`], treating the decoded value as a big-endian
+/// arbitrary-precision number.
+///
+/// A leading `alphabet`-first character yields a leading zero byte, the
+/// inverse of [`encode_bytes_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::{decode_bytes_with, Alphabet};
+///
+/// assert_eq!(decode_bytes_with("AAB", &Alphabet::default()), Ok(vec![0, 0, 1]));
+/// assert_eq!(decode_bytes_with("", &Alphabet::default()), Ok(vec![]));
+/// ```
+pub fn decode_bytes_with(input: &str, alphabet: &Alphabet) -> Result<Vec<u8>, Base62Error> {
+    if input.is_empty() {
+        return Ok(Vec::new());
+    }
+    let zero_char = alphabet.to_char(0).unwrap_or_else(|| unreachable!());
+    let leading_zeros = input.chars().take_while(|&c| c == zero_char).count();
+    let reverse = alphabet.reverse_lookup();
+    let mut magnitude: Vec<u8> = vec![];
+    for c in input.chars().skip(leading_zeros) {
+        let digit = *reverse.get(&c).ok_or(Base62Error::NonAlphanumeric)?;
+        mul_add62(&mut magnitude, digit as u8);
+    }
+    magnitude.reverse();
+    let mut bytes = vec![0u8; leading_zeros];
+    bytes.extend(magnitude);
+    Ok(bytes)
+}
+
+/// Converts a string in Base62 into a byte vector.
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::decode_bytes;
+///
+/// assert_eq!(decode_bytes("AAB"), Ok(vec![0, 0, 1]));
+/// assert_eq!(decode_bytes(""), Ok(vec![]));
+/// ```
+pub fn decode_bytes(input: &str) -> Result<Vec<u8>, Base62Error> {
+    decode_bytes_with(input, &Alphabet::default())
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The unsigned integer widths that [`encode_int`]/[`decode_int`] can
+/// target.
+///
+/// This trait is sealed: it is implemented only for `u8`, `u16`, `u32`,
+/// `u64`, `u128` and `usize`, so it cannot be implemented outside this
+/// crate.
+pub trait Base62Int: sealed::Sealed + Copy + Sized {
+    #[doc(hidden)]
+    const ZERO: Self;
+    /// The maximum number of characters [`encode_int`] can produce for
+    /// this width, i.e. the encoded length of `Self::MAX`. Callers can use
+    /// this to preallocate a buffer for [`encode_into`].
+    const MAX_ENCODED_LEN: usize;
+    #[doc(hidden)]
+    fn checked_mul_base(self) -> Option<Self>;
+    #[doc(hidden)]
+    fn checked_add_digit(self, digit: usize) -> Option<Self>;
+    #[doc(hidden)]
+    fn div_rem_base(self) -> (Self, usize);
+    #[doc(hidden)]
+    fn is_zero(self) -> bool;
+}
+
+/// The number of Base62 digits needed to represent `max`, found by counting
+/// how many times it can be divided by [`BASE`](BASE) before reaching zero.
+const fn digits_to_represent(max: u128) -> usize {
+    let mut value = max;
+    let mut len = 0;
+    loop {
+        value /= BASE as u128;
+        len += 1;
+        if value == 0 {
+            break;
+        }
+    }
+    len
+}
+
+macro_rules! base62_int_impl {
+    ($($t:ty)*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl Base62Int for $t {
+            const ZERO: Self = 0;
+            const MAX_ENCODED_LEN: usize = digits_to_represent(<$t>::MAX as u128);
+
+            fn checked_mul_base(self) -> Option<Self> {
+                self.checked_mul(BASE as $t)
+            }
+
+            fn checked_add_digit(self, digit: usize) -> Option<Self> {
+                self.checked_add(digit as $t)
+            }
+
+            fn div_rem_base(self) -> (Self, usize) {
+                (self / BASE as $t, (self % BASE as $t) as usize)
+            }
+
+            fn is_zero(self) -> bool {
+                self == 0
+            }
+        }
+    )*};
+}
+
+base62_int_impl! { u8 u16 u32 u64 u128 usize }
+
+/// The maximum [`Base62Int::MAX_ENCODED_LEN`] across all widths this crate
+/// supports (`u128`'s), used to size the stack buffer in [`encode_into`].
+const MAX_STACK_ENCODED_LEN: usize = u128::MAX_ENCODED_LEN;
+
+/// Returns the maximum number of characters an [`encode_int`]/[`encode`]
+/// call can produce for `T`, so callers can preallocate a buffer of that
+/// size for [`encode_into`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::max_encoded_len;
+///
+/// assert_eq!(max_encoded_len::<u8>(), 2);
+/// assert_eq!(max_encoded_len::<u128>(), 22);
+/// ```
+pub fn max_encoded_len<T: Base62Int>() -> usize {
+    T::MAX_ENCODED_LEN
+}
+
+/// Appends an integer of any [`Base62Int`] width to `buf` in Base62, using
+/// a custom [`Alphabet`], without allocating a new [`String`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::{encode_into_with, max_encoded_len, Alphabet};
+///
+/// let mut buf = String::with_capacity(max_encoded_len::<u128>());
+/// encode_into_with(123u128, &Alphabet::default(), &mut buf);
+/// assert_eq!(buf, "B9");
+/// ```
+pub fn encode_into_with<T: Base62Int>(num: T, alphabet: &Alphabet, buf: &mut String) {
+    let mut stack = ['\0'; MAX_STACK_ENCODED_LEN];
+    let mut len = 0;
+    let mut n = num;
+    while !n.is_zero() {
+        let (quotient, rem) = n.div_rem_base();
+        n = quotient;
+        stack[len] = match alphabet.to_char(rem) {
+            Some(c) => c,
+            None => unreachable!(),
+        };
+        len += 1;
+    }
+    buf.extend(stack[..len].iter().rev());
+}
+
+/// Appends an integer of any [`Base62Int`] width to `buf` in Base62,
+/// without allocating a new [`String`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::{encode_into, max_encoded_len};
+///
+/// let mut buf = String::with_capacity(max_encoded_len::<u16>());
+/// encode_into(7u16, &mut buf);
+/// assert_eq!(buf, "H");
+/// ```
+pub fn encode_into<T: Base62Int>(num: T, buf: &mut String) {
+    encode_into_with(num, &Alphabet::default(), buf)
+}
+
+/// Converts an integer of any [`Base62Int`] width into a string in Base62
+/// using a custom [`Alphabet`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::{encode_int_with, Alphabet};
+///
+/// assert_eq!(encode_int_with(123u128, &Alphabet::default()), "B9");
+/// ```
+pub fn encode_int_with<T: Base62Int>(num: T, alphabet: &Alphabet) -> String {
+    let mut digits = vec![];
+    let mut n = num;
+    while !n.is_zero() {
+        let (quotient, rem) = n.div_rem_base();
+        n = quotient;
+        match alphabet.to_char(rem) {
+            Some(c) => digits.push(c),
+            None => unreachable!(),
+        };
+    }
+    digits.iter().rev().collect()
+}
+
+/// Converts an integer of any [`Base62Int`] width into a string in Base62.
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::encode_int;
+///
+/// assert_eq!(encode_int(7u16), "H");
+/// assert_eq!(encode_int(123u128), "B9");
+/// ```
+pub fn encode_int<T: Base62Int>(num: T) -> String {
+    encode_int_with(num, &Alphabet::default())
+}
+
+/// Converts a string in Base62 into an integer of any [`Base62Int`] width
+/// using a custom [`Alphabet`].
+///
+/// [`Base62Error::Overflow`] is reported relative to `T`'s own maximum
+/// value, not `usize`'s.
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::{decode_int_with, Alphabet};
+///
+/// assert_eq!(decode_int_with::<u128>("B9", &Alphabet::default()), Ok(123));
+/// ```
+pub fn decode_int_with<T: Base62Int>(input: &str, alphabet: &Alphabet) -> Result<T, Base62Error> {
+    let reverse = alphabet.reverse_lookup();
+    input.chars().try_fold(T::ZERO, |acc, c| {
+        reverse
+            .get(&c)
+            .ok_or(Base62Error::NonAlphanumeric)
+            .and_then(|&x| {
+                acc.checked_mul_base()
+                    .and_then(|mul| mul.checked_add_digit(x))
+                    .ok_or(Base62Error::Overflow)
+            })
     })
 }
 
+/// Converts a string in Base62 into an integer of any [`Base62Int`] width.
+///
+/// [`Base62Error::Overflow`] is reported relative to `T`'s own maximum
+/// value, not `usize`'s, so e.g. decoding into a `u8` overflows far sooner
+/// than decoding into a `u128`.
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::{decode_int, Base62Error};
+///
+/// assert_eq!(decode_int::<u128>("B9"), Ok(123));
+/// assert_eq!(decode_int::<u8>("zzz"), Err(Base62Error::Overflow));
+/// ```
+pub fn decode_int<T: Base62Int>(input: &str) -> Result<T, Base62Error> {
+    decode_int_with(input, &Alphabet::default())
+}
+
+macro_rules! width_suffixed_helpers {
+    ($(($t:ty, $encode:ident, $decode:ident)),* $(,)?) => {$(
+        #[doc = concat!("Converts a [`", stringify!($t), "`] into a string in Base62.")]
+        pub fn $encode(num: $t) -> String {
+            encode_int(num)
+        }
+
+        #[doc = concat!("Converts a string in Base62 into a [`", stringify!($t), "`].")]
+        pub fn $decode(input: &str) -> Result<$t, Base62Error> {
+            decode_int(input)
+        }
+    )*};
+}
+
+width_suffixed_helpers! {
+    (u8, encode_u8, decode_u8),
+    (u16, encode_u16, decode_u16),
+    (u32, encode_u32, decode_u32),
+    (u64, encode_u64, decode_u64),
+    (u128, encode_u128, decode_u128),
+}
+
+/// A [`usize`] newtype that parses from and formats as Base62 through the
+/// standard [`FromStr`](core::str::FromStr)/[`Display`](core::fmt::Display)
+/// traits, for use in `serde`/`clap`-style contexts that expect them.
+///
+/// The plain [`encode`]/[`decode`] functions remain for callers who just
+/// want a [`usize`].
+///
+/// # Examples
+///
+/// ```rust
+/// use base62num::Base62;
+///
+/// let parsed: Base62 = "B9".parse().unwrap();
+/// assert_eq!(parsed, Base62(123));
+/// assert_eq!(parsed.to_string(), "B9");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base62(pub usize);
+
+impl core::str::FromStr for Base62 {
+    type Err = Base62Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        decode(input).map(Base62)
+    }
+}
+
+impl core::fmt::Display for Base62 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{}", encode(self.0))
+    }
+}
+
+impl core::convert::TryFrom<&str> for Base62 {
+    type Error = Base62Error;
+
+    /// # Examples
+    ///
+    /// ```rust
+    /// use base62num::Base62;
+    /// use core::convert::TryFrom;
+    ///
+    /// assert_eq!(Base62::try_from("B9"), Ok(Base62(123)));
+    /// ```
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        input.parse()
+    }
+}
+
+impl From<usize> for Base62 {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use base62num::Base62;
+    ///
+    /// assert_eq!(Base62::from(123), Base62(123));
+    /// ```
+    fn from(num: usize) -> Self {
+        Base62(num)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
+    use core::convert::TryFrom;
 
     #[test]
     fn encode_pass() {
@@ -159,4 +710,126 @@ mod tests {
             Err(Base62Error::Overflow)
         );
     }
+
+    fn gmp_alphabet() -> Alphabet {
+        let mut chars = ['\0'; 62];
+        for (i, c) in "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+            .chars()
+            .enumerate()
+        {
+            chars[i] = c;
+        }
+        Alphabet::new(chars).unwrap()
+    }
+
+    #[test]
+    fn alphabet_new_rejects_duplicates() {
+        let mut chars = ['0'; 62];
+        chars[1] = '0';
+        assert_eq!(Alphabet::new(chars), Err(Base62Error::InvalidAlphabet));
+    }
+
+    #[test]
+    fn alphabet_new_rejects_non_alphanumeric() {
+        let mut chars = ['\0'; 62];
+        for (i, c) in ALPHANUMERIC.chars().enumerate() {
+            chars[i] = c;
+        }
+        chars[0] = '*';
+        assert_eq!(Alphabet::new(chars), Err(Base62Error::InvalidAlphabet));
+    }
+
+    #[test]
+    fn encode_decode_with_custom_alphabet() {
+        let gmp = gmp_alphabet();
+        assert_eq!(encode_with(123, &gmp), "1z");
+        assert_eq!(decode_with("1z", &gmp), Ok(123));
+        assert_eq!(
+            decode_with("Base*62", &gmp),
+            Err(Base62Error::NonAlphanumeric)
+        );
+    }
+
+    #[test]
+    fn encode_bytes_pass() {
+        assert_eq!(encode_bytes(&[]), "");
+        assert_eq!(encode_bytes(&[0, 0, 1]), "AAB");
+        assert_eq!(encode_bytes(&[0, 0]), "AA");
+        assert_eq!(encode_bytes(&[123]), encode(123));
+        assert_eq!(encode_bytes(&[1, 0, 0]), encode(1 << 16));
+    }
+
+    #[test]
+    fn decode_bytes_pass() {
+        assert_eq!(decode_bytes(""), Ok(vec![]));
+        assert_eq!(decode_bytes("AAB"), Ok(vec![0, 0, 1]));
+        assert_eq!(decode_bytes("AA"), Ok(vec![0, 0]));
+        assert_eq!(
+            decode_bytes("Base*62"),
+            Err(Base62Error::NonAlphanumeric)
+        );
+    }
+
+    #[test]
+    fn encode_decode_bytes_roundtrip() {
+        let bytes = vec![0, 255, 1, 0, 16, 0];
+        assert_eq!(decode_bytes(&encode_bytes(&bytes)), Ok(bytes));
+    }
+
+    #[test]
+    fn encode_decode_int_pass() {
+        assert_eq!(encode_int(7u16), "H");
+        assert_eq!(encode_int(123u128), "B9");
+        assert_eq!(decode_int::<u16>("H"), Ok(7));
+        assert_eq!(decode_int::<u128>("B9"), Ok(123));
+    }
+
+    #[test]
+    fn decode_int_overflow_is_relative_to_target_width() {
+        assert_eq!(decode_int::<u8>("zzz"), Err(Base62Error::Overflow));
+        assert_eq!(decode_u8(&encode_u8(200)), Ok(200));
+        assert_eq!(decode_u8("100"), Err(Base62Error::Overflow));
+    }
+
+    #[test]
+    fn width_suffixed_helpers_roundtrip() {
+        assert_eq!(decode_u8(&encode_u8(200)), Ok(200));
+        assert_eq!(decode_u64(&encode_u64(u64::MAX)), Ok(u64::MAX));
+        assert_eq!(decode_u128(&encode_u128(u128::MAX)), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn max_encoded_len_matches_max_value() {
+        assert_eq!(max_encoded_len::<u8>(), encode_u8(u8::MAX).len());
+        assert_eq!(max_encoded_len::<u16>(), encode_u16(u16::MAX).len());
+        assert_eq!(max_encoded_len::<u128>(), encode_u128(u128::MAX).len());
+    }
+
+    #[test]
+    fn encode_into_appends_without_clearing() {
+        let mut buf = String::from("prefix-");
+        encode_into(123u128, &mut buf);
+        assert_eq!(buf, "prefix-B9");
+    }
+
+    #[test]
+    fn base62_from_str_and_display() {
+        let parsed: Base62 = "B9".parse().unwrap();
+        assert_eq!(parsed, Base62(123));
+        assert_eq!(parsed.to_string(), "B9");
+        assert_eq!(
+            "Base*62".parse::<Base62>(),
+            Err(Base62Error::NonAlphanumeric)
+        );
+    }
+
+    #[test]
+    fn base62_try_from_and_from() {
+        assert_eq!(Base62::try_from("B9"), Ok(Base62(123)));
+        assert_eq!(
+            Base62::try_from("Base*62"),
+            Err(Base62Error::NonAlphanumeric)
+        );
+        assert_eq!(Base62::from(123), Base62(123));
+    }
 }